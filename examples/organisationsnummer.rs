@@ -1,23 +1,18 @@
-use personnummer::{Personnummer, PersonnummerError};
-use std::env;
+use clap::Parser;
+use organisationsnummer::Organisationsnummer;
 
-fn main() -> Result<(), PersonnummerError> {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        println!("Usage: cargo run --example organisationsnummer <organisationsnummer>");
-        return Err(PersonnummerError::InvalidInput);
-    }
-
-    let org = Personnummer::new(&args[1])?;
+/// Validate a Swedish organization number.
+#[derive(Parser)]
+struct Args {
+    /// The organization number to validate, e.g. 556016-0680
+    org: Organisationsnummer,
+}
 
-    if org.valid() {
-        println!(
-            "The company with organization number {}",
-            org.format().long(),
-        );
-    } else {
-        println!("invalid organization number provided");
-    }
+fn main() {
+    let args = Args::parse();
 
-    Ok(())
+    println!(
+        "The company with organization number {}",
+        args.org.format().long(),
+    );
 }
@@ -0,0 +1,56 @@
+use crate::Organisationsnummer;
+use clap::builder::{StringValueParser, TypedValueParser, ValueParserFactory};
+use clap::error::{Error as ClapError, ErrorKind};
+use clap::{Arg, Command};
+use std::ffi::OsStr;
+
+/// Parses and validates an [`Organisationsnummer`] directly as a `clap` argument value.
+#[derive(Clone)]
+pub struct OrganisationsnummerValueParser;
+
+impl TypedValueParser for OrganisationsnummerValueParser {
+    type Value = Organisationsnummer;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, ClapError> {
+        let org = StringValueParser::new().parse_ref(cmd, arg, value)?;
+
+        Organisationsnummer::new(&org)
+            .map_err(|err| ClapError::raw(ErrorKind::ValueValidation, format!("{}\n", err)))
+    }
+}
+
+impl ValueParserFactory for Organisationsnummer {
+    type Parser = OrganisationsnummerValueParser;
+
+    fn value_parser() -> Self::Parser {
+        OrganisationsnummerValueParser
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ref_accepts_valid_org() {
+        let cmd = Command::new("test");
+        let result =
+            OrganisationsnummerValueParser.parse_ref(&cmd, None, OsStr::new("556036-0793"));
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_parse_ref_rejects_invalid_org() {
+        let cmd = Command::new("test");
+        let result =
+            OrganisationsnummerValueParser.parse_ref(&cmd, None, OsStr::new("not-an-org-number"));
+
+        assert!(result.is_err());
+    }
+}
@@ -1,9 +1,16 @@
 #[macro_use]
 extern crate lazy_static;
 
+#[cfg(feature = "clap")]
+mod clap_support;
+#[cfg(feature = "serde")]
+mod serde_support;
+#[cfg(feature = "serde")]
+pub use serde_support::short;
+
 use personnummer::{Personnummer, PersonnummerError};
 use regex::{Match, Regex};
-use std::{convert::TryFrom, error::Error, fmt};
+use std::{convert::TryFrom, error::Error, fmt, str::FromStr};
 
 lazy_static! {
     static ref ORG_REGEX: Regex =
@@ -11,24 +18,51 @@ lazy_static! {
 }
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum OrganisationsnummerError {
-    InvalidInput,
+    /// The input does not match the expected organization number shape.
+    BadFormat,
+    /// The number carries a leading two-digit prefix other than `16`.
+    InvalidPrefix,
+    /// The two-digit group formed by the third and fourth digits is below 20.
+    InvalidType,
+    /// The two-digit group formed by the first and second digits is below 10 (a leading zero).
+    LeadingZeroGroup,
+    /// The Luhn checksum digit does not match.
+    InvalidChecksum,
 }
 
 impl fmt::Display for OrganisationsnummerError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            OrganisationsnummerError::InvalidInput => write!(f, "Invalid format"),
+            OrganisationsnummerError::BadFormat => write!(f, "invalid format"),
+            OrganisationsnummerError::InvalidPrefix => {
+                write!(f, "invalid prefix, may only be prefixed with 16")
+            }
+            OrganisationsnummerError::InvalidType => {
+                write!(f, "invalid type, third digit must be 20 or greater")
+            }
+            OrganisationsnummerError::LeadingZeroGroup => {
+                write!(
+                    f,
+                    "invalid format, second digit may not start with a leading zero"
+                )
+            }
+            OrganisationsnummerError::InvalidChecksum => write!(f, "invalid checksum"),
         }
     }
 }
 
 impl Error for OrganisationsnummerError {}
 
-#[allow(dead_code)]
 /// Organisationsnummer holds relevant data to check for valid organization numbers.
+///
+/// The personal-identity-number case is not stored as a parsed `Personnummer` so that
+/// `Organisationsnummer` itself stays `Clone` (a `Personnummer` is reconstructed on demand from
+/// `number`, which is cheap since it is just a regex match and a Luhn check).
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Organisationsnummer {
-    personnummer: Option<Personnummer>,
+    is_personnummer: bool,
     number: String,
 }
 
@@ -49,6 +83,12 @@ impl FormattedOrganisationsnummer {
     pub fn short(&self) -> String {
         self.short.clone()
     }
+
+    /// Returns the canonical 10-digit tax identification number used in Skatteverket filings,
+    /// such as Kontrolluppgift submissions: no separator and no `16` prefix.
+    pub fn tax_id(&self) -> String {
+        self.short().replace('+', "")
+    }
 }
 
 impl TryFrom<&str> for Organisationsnummer {
@@ -57,7 +97,7 @@ impl TryFrom<&str> for Organisationsnummer {
     fn try_from(org: &str) -> Result<Self, OrganisationsnummerError> {
         let caps = ORG_REGEX
             .captures(org)
-            .ok_or(OrganisationsnummerError::InvalidInput)?;
+            .ok_or(OrganisationsnummerError::BadFormat)?;
 
         let match_to_u32 =
             |m: Option<Match<'_>>| -> u32 { m.unwrap().as_str().parse::<u32>().unwrap_or(0) };
@@ -72,7 +112,7 @@ impl TryFrom<&str> for Organisationsnummer {
         // May only be prefixed with 16.
         if prefix != 0 {
             if prefix != 16 {
-                return Err(OrganisationsnummerError::InvalidInput);
+                return Err(OrganisationsnummerError::InvalidPrefix);
             } else {
                 number = number[2..].to_string();
             }
@@ -82,25 +122,76 @@ impl TryFrom<&str> for Organisationsnummer {
 
         // Third digit bust be more than 20.
         if third < 20 {
-            return Err(OrganisationsnummerError::InvalidInput);
+            return Err(OrganisationsnummerError::InvalidType);
         }
 
         let second = match_to_u32(caps.get(2));
 
         // Second digit may not start with leading 0.
         if second < 10 {
-            return Err(OrganisationsnummerError::InvalidInput);
+            return Err(OrganisationsnummerError::LeadingZeroGroup);
         }
 
         // Luhn checksum must be valid.
         if !luhn(number.clone()) {
-            return Err(OrganisationsnummerError::InvalidInput);
+            return Err(OrganisationsnummerError::InvalidChecksum);
         }
 
-        return Ok(Organisationsnummer {
-            personnummer: None,
+        Ok(Organisationsnummer {
+            is_personnummer: false,
             number: number.clone(),
-        });
+        })
+    }
+}
+
+impl FromStr for Organisationsnummer {
+    type Err = OrganisationsnummerError;
+
+    fn from_str(org: &str) -> Result<Self, Self::Err> {
+        Organisationsnummer::new(org)
+    }
+}
+
+/// OrganizationType is the kind of legal entity an organization number identifies, derived from
+/// its leading digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrganizationType {
+    SoleProprietor,
+    Estate,
+    StateCountyMunicipalityParish,
+    ForeignCompany,
+    LimitedCompany,
+    SimpleCompany,
+    EconomicAssociation,
+    NonProfitOrFoundation,
+    TradingOrLimitedPartnership,
+    Unknown,
+}
+
+impl fmt::Display for OrganizationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            OrganizationType::SoleProprietor => "Enskild firma",
+            OrganizationType::Estate => "Dödsbon",
+            OrganizationType::StateCountyMunicipalityParish => {
+                "Stat, landsting, kommun eller församling"
+            }
+            OrganizationType::ForeignCompany => {
+                "Utländska företag som bedriver näringsverksamhet eller äger fastigheter i Sverige"
+            }
+            OrganizationType::LimitedCompany => "Aktiebolag",
+            OrganizationType::SimpleCompany => "Enkelt bolag",
+            OrganizationType::EconomicAssociation => {
+                "Ekonomisk förening eller bostadsrättsförening"
+            }
+            OrganizationType::NonProfitOrFoundation => "'Ideella förening och stiftelse",
+            OrganizationType::TradingOrLimitedPartnership => {
+                "Handelsbolag, kommanditbolag och enkelt bolag"
+            }
+            OrganizationType::Unknown => "Okänt",
+        };
+
+        write!(f, "{}", text)
     }
 }
 
@@ -108,8 +199,8 @@ impl Organisationsnummer {
     /// Returns a new instance of a Organisationsnummer.
     pub fn new(org: &str) -> Result<Organisationsnummer, OrganisationsnummerError> {
         match Personnummer::new(org) {
-            Ok(pnr) => Ok(Organisationsnummer {
-                personnummer: Some(pnr),
+            Ok(_) => Ok(Organisationsnummer {
+                is_personnummer: true,
                 number: org.to_string().replace("-", ""),
             }),
             Err(_) => Organisationsnummer::try_from(org),
@@ -128,66 +219,73 @@ impl Organisationsnummer {
 
     /// Format organization number with or without separator.
     pub fn format(&self) -> FormattedOrganisationsnummer {
-        let formatted = match &self.personnummer {
-            Some(pnr) => {
-                let f = pnr.format();
-                let s = f.short();
-
-                let mut l = f.long();
-                if pnr.get_age() >= 100 {
-                    l = l.replace("-", "+");
-                }
-
-                FormattedOrganisationsnummer {
-                    long: l[2..].to_string(),
-                    short: s[0..6].to_string() + &s[7..].to_string(),
-                }
-            },
-            None => FormattedOrganisationsnummer {
+        let formatted = if self.is_personnummer {
+            let pnr = self.personnummer().expect("number was validated in new()");
+            let f = pnr.format();
+            let s = f.short();
+
+            let mut l = f.long();
+            if pnr.get_age() >= 100 {
+                l = l.replace("-", "+");
+            }
+
+            FormattedOrganisationsnummer {
+                long: l[2..].to_string(),
+                short: s[0..6].to_string() + &s[7..],
+            }
+        } else {
+            FormattedOrganisationsnummer {
                 long: format!("{}-{}", &self.number[..6], &self.number[6..]),
                 short: self.number.clone(),
-            },
+            }
         };
 
         formatted
     }
 
-    /// Get the organization type.
-    pub fn r#type(&self) -> String {
-        let first = match &self.personnummer {
-            Some(_) => 0,
-            None => self
-                .number
+    /// Get the organization type, as the enum variant matching the leading digit.
+    pub fn type_code(&self) -> OrganizationType {
+        let first = if self.is_personnummer {
+            0
+        } else {
+            self.number
                 .chars()
                 .next()
                 .unwrap()
                 .to_digit(10)
-                .unwrap_or(0),
+                .unwrap_or(0)
         };
 
-        let r#type = match first {
-            0 => "Enskild firma",
-            1 => "Dödsbon",
-            2 => "Stat, landsting, kommun eller församling",
-            3 => {
-                "Utländska företag som bedriver näringsverksamhet eller äger fastigheter i Sverige"
-            }
-            5 => "Aktiebolag",
-            6 => "Enkelt bolag",
-            7 => "Ekonomisk förening eller bostadsrättsförening",
-            8 => "'Ideella förening och stiftelse",
-            9 => "Handelsbolag, kommanditbolag och enkelt bolag",
-            _ => "Okänt",
-        };
+        match first {
+            0 => OrganizationType::SoleProprietor,
+            1 => OrganizationType::Estate,
+            2 => OrganizationType::StateCountyMunicipalityParish,
+            3 => OrganizationType::ForeignCompany,
+            5 => OrganizationType::LimitedCompany,
+            6 => OrganizationType::SimpleCompany,
+            7 => OrganizationType::EconomicAssociation,
+            8 => OrganizationType::NonProfitOrFoundation,
+            9 => OrganizationType::TradingOrLimitedPartnership,
+            _ => OrganizationType::Unknown,
+        }
+    }
 
-        r#type.to_string()
+    /// Get the organization type. Same as `type_code().to_string()`.
+    pub fn r#type(&self) -> String {
+        self.type_code().to_string()
     }
 
     /// Get organization vat number.
     pub fn vat_number(&self) -> String {
-        let number = match &self.personnummer {
-            Some(pnr) => pnr.format().long()[2..13].to_string().replace("-", ""),
-            None => self.number.clone(),
+        let number = if self.is_personnummer {
+            self.personnummer()
+                .expect("number was validated in new()")
+                .format()
+                .long()[2..13]
+                .to_string()
+                .replace("-", "")
+        } else {
+            self.number.clone()
         };
 
         format!("SE{}01", number)
@@ -200,10 +298,7 @@ impl Organisationsnummer {
 
     /// Determine if personnummer or not.
     pub fn is_personnummer(&self) -> bool {
-        match &self.personnummer {
-            Some(_) => true,
-            None => false,
-        }
+        self.is_personnummer
     }
 }
 
@@ -219,7 +314,7 @@ fn luhn(value: String) -> bool {
             acc + if value > 9 { value - 9 } else { value }
         });
 
-    (10 - (checksum as u8 % 10)) % 10 == 0
+    (10 - (checksum as u8 % 10)).is_multiple_of(10)
 }
 
 #[cfg(test)]
@@ -243,8 +338,8 @@ mod tests {
             "https://raw.githubusercontent.com/organisationsnummer/meta/main/testdata/list.json",
         )
         .unwrap();
-        let list = res.json::<Vec<TestItem>>().unwrap();
-        list
+
+        res.json::<Vec<TestItem>>().unwrap()
     }
 
     #[test]
@@ -362,12 +457,11 @@ mod tests {
                 continue;
             }
 
-
             assert!(Organisationsnummer::parse(item.long_format.as_str())
                 .unwrap()
                 .valid());
 
-                let org = Organisationsnummer::parse(item.input.as_str()).unwrap();
+            let org = Organisationsnummer::parse(item.input.as_str()).unwrap();
             assert!(org.valid());
             assert_eq!(org.format().short(), item.short_format);
             assert_eq!(org.format().long(), item.long_format);
@@ -376,4 +470,106 @@ mod tests {
             assert_eq!(org.vat_number(), item.vat_number);
         }
     }
+
+    // A known-valid limited company number (Google Sweden AB), used below so the remaining
+    // tests don't depend on the network-fetched list.
+
+    const VALID_ORG: &str = "556036-0793";
+
+    #[test]
+    fn test_type_code() {
+        let org = Organisationsnummer::new(VALID_ORG).unwrap();
+        assert_eq!(org.type_code(), OrganizationType::LimitedCompany);
+        assert_eq!(org.r#type(), "Aktiebolag");
+    }
+
+    #[test]
+    fn test_organization_type_display() {
+        assert_eq!(
+            OrganizationType::SoleProprietor.to_string(),
+            "Enskild firma"
+        );
+        assert_eq!(OrganizationType::LimitedCompany.to_string(), "Aktiebolag");
+        assert_eq!(OrganizationType::Unknown.to_string(), "Okänt");
+    }
+
+    #[test]
+    fn test_tax_id() {
+        let org = Organisationsnummer::new(VALID_ORG).unwrap();
+        assert_eq!(org.format().tax_id(), org.format().short());
+        assert_eq!(org.format().tax_id(), "5560360793");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let org: Organisationsnummer = VALID_ORG.parse().unwrap();
+        assert_eq!(org, Organisationsnummer::new(VALID_ORG).unwrap());
+    }
+
+    #[test]
+    fn test_error_bad_format() {
+        let err = Organisationsnummer::new("not-an-org-number").unwrap_err();
+        assert!(matches!(err, OrganisationsnummerError::BadFormat));
+    }
+
+    #[test]
+    fn test_error_invalid_prefix() {
+        let err = Organisationsnummer::new("12556036-0793").unwrap_err();
+        assert!(matches!(err, OrganisationsnummerError::InvalidPrefix));
+    }
+
+    #[test]
+    fn test_error_invalid_type() {
+        let err = Organisationsnummer::new("550593-0793").unwrap_err();
+        assert!(matches!(err, OrganisationsnummerError::InvalidType));
+    }
+
+    #[test]
+    fn test_error_leading_zero_group() {
+        let err = Organisationsnummer::new("056036-0793").unwrap_err();
+        assert!(matches!(err, OrganisationsnummerError::LeadingZeroGroup));
+    }
+
+    #[test]
+    fn test_error_invalid_checksum() {
+        let err = Organisationsnummer::new("556036-0792").unwrap_err();
+        assert!(matches!(err, OrganisationsnummerError::InvalidChecksum));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let org = Organisationsnummer::new(VALID_ORG).unwrap();
+        let json = serde_json::to_string(&org).unwrap();
+        assert_eq!(json, "\"556036-0793\"");
+
+        let decoded: Organisationsnummer = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, org);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_rejects_invalid_input() {
+        let result: Result<Organisationsnummer, _> = serde_json::from_str("\"not-an-org-number\"");
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_short_form() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Wrapper {
+            #[serde(with = "crate::short")]
+            org: Organisationsnummer,
+        }
+
+        let wrapper = Wrapper {
+            org: Organisationsnummer::new(VALID_ORG).unwrap(),
+        };
+        let json = serde_json::to_string(&wrapper).unwrap();
+        assert_eq!(json, "{\"org\":\"5560360793\"}");
+
+        let decoded: Wrapper = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.org, wrapper.org);
+    }
 }
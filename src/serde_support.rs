@@ -0,0 +1,51 @@
+use crate::Organisationsnummer;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes to the canonical long format (with separator), i.e. `self.format().long()`.
+///
+/// To serialize the short form instead, annotate the field with
+/// `#[serde(with = "organisationsnummer::short")]`, which uses [`short::serialize`] /
+/// [`short::deserialize`] below.
+impl Serialize for Organisationsnummer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.format().long())
+    }
+}
+
+/// Deserializes from a string through [`Organisationsnummer::new`], so the Luhn checksum and the
+/// second/third-digit rules are enforced during deserialization, rejecting invalid input with a
+/// `serde::de::Error`. Accepts either the long or short form as input.
+impl<'de> Deserialize<'de> for Organisationsnummer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let org = String::deserialize(deserializer)?;
+        Organisationsnummer::new(&org).map_err(de::Error::custom)
+    }
+}
+
+/// Serializes/deserializes an [`Organisationsnummer`] in its short (no-separator) form, for use
+/// with `#[serde(with = "organisationsnummer::short")]` on a field. Deserialization still
+/// validates through [`Organisationsnummer::new`] and accepts either form as input, same as the
+/// default `Deserialize` impl; only the serialized form differs.
+pub mod short {
+    use super::*;
+
+    pub fn serialize<S>(org: &Organisationsnummer, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&org.format().short())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Organisationsnummer, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Organisationsnummer::deserialize(deserializer)
+    }
+}